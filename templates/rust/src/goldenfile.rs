@@ -0,0 +1,218 @@
+//! Golden-file test harness.
+//!
+//! Tests that produce output (a rendered table, a serialized report, ...)
+//! register a writer through a [`Mint`], write to it like any other file,
+//! and let `Drop` compare the result against a checked-in "golden" copy.
+//! Set `REGENERATE_GOLDENFILES=1` to overwrite the golden copies instead of
+//! comparing, so intentional output changes show up as a reviewable diff.
+
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+const REGENERATE_VAR: &str = "REGENERATE_GOLDENFILES";
+
+/// Hands out writers for golden-file comparisons and reconciles them
+/// against the checked-in copies when dropped.
+pub struct Mint {
+    base_dir: PathBuf,
+    regenerate: bool,
+    files: Vec<PendingFile>,
+}
+
+struct PendingFile {
+    golden_path: PathBuf,
+    tmp_path: PathBuf,
+}
+
+impl Mint {
+    /// Create a `Mint` rooted at `base_dir`, where golden files live.
+    pub fn new(base_dir: impl Into<PathBuf>) -> Self {
+        Mint {
+            base_dir: base_dir.into(),
+            regenerate: env::var(REGENERATE_VAR).is_ok(),
+            files: Vec::new(),
+        }
+    }
+
+    /// Register a golden file at `relative_path` and return a writer for
+    /// it. The writer targets a temp file; the golden file itself is only
+    /// touched once the `Mint` is dropped, so a failing test never leaves
+    /// a half-written golden file behind.
+    pub fn new_goldenfile(&mut self, relative_path: impl AsRef<Path>) -> io::Result<impl Write> {
+        let golden_path = self.base_dir.join(relative_path.as_ref());
+        if let Some(parent) = golden_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let tmp_path = golden_path.with_extension(format!(
+            "{}.tmp",
+            golden_path
+                .extension()
+                .and_then(|e| e.to_str())
+                .unwrap_or("out")
+        ));
+        let file = File::create(&tmp_path)?;
+        self.files.push(PendingFile {
+            golden_path,
+            tmp_path: tmp_path.clone(),
+        });
+        Ok(file)
+    }
+
+    fn reconcile(&mut self) {
+        for pending in &self.files {
+            let result = if self.regenerate {
+                fs::rename(&pending.tmp_path, &pending.golden_path)
+            } else {
+                let diff = diff_against_golden(&pending.tmp_path, &pending.golden_path);
+                let _ = fs::remove_file(&pending.tmp_path);
+                match diff {
+                    Ok(None) => Ok(()),
+                    Ok(Some(diff)) => {
+                        panic!(
+                            "golden file mismatch for {}:\n{}\n\nrun with {}=1 to regenerate",
+                            pending.golden_path.display(),
+                            diff,
+                            REGENERATE_VAR
+                        );
+                    }
+                    Err(e) => Err(e),
+                }
+            };
+            if let Err(e) = result {
+                panic!(
+                    "failed to reconcile golden file {}: {}",
+                    pending.golden_path.display(),
+                    e
+                );
+            }
+        }
+    }
+}
+
+impl Drop for Mint {
+    fn drop(&mut self) {
+        // Avoid a double panic if we're already unwinding from a failed
+        // comparison or an earlier test assertion.
+        if !std::thread::panicking() {
+            self.reconcile();
+        }
+    }
+}
+
+/// Return a unified-style line diff if `tmp_path` and `golden_path` differ,
+/// or `None` if they match (or the golden file doesn't exist yet and the
+/// new content is empty).
+fn diff_against_golden(tmp_path: &Path, golden_path: &Path) -> io::Result<Option<String>> {
+    let actual = read_to_string(tmp_path)?;
+    let expected = match read_to_string(golden_path) {
+        Ok(s) => s,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e),
+    };
+
+    if actual == expected {
+        return Ok(None);
+    }
+
+    let mut diff = String::new();
+    for line in diff_lines(&expected, &actual) {
+        diff.push_str(&line);
+        diff.push('\n');
+    }
+    Ok(Some(diff))
+}
+
+fn read_to_string(path: &Path) -> io::Result<String> {
+    let mut s = String::new();
+    File::open(path)?.read_to_string(&mut s)?;
+    Ok(s)
+}
+
+/// A minimal unified-diff-flavored line comparison: `-` for expected-only
+/// lines, `+` for actual-only lines, ` ` for lines that match.
+fn diff_lines(expected: &str, actual: &str) -> Vec<String> {
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+    let max = expected_lines.len().max(actual_lines.len());
+    let mut out = Vec::new();
+    for i in 0..max {
+        match (expected_lines.get(i), actual_lines.get(i)) {
+            (Some(e), Some(a)) if e == a => out.push(format!(" {}", e)),
+            (Some(e), Some(a)) => {
+                out.push(format!("-{}", e));
+                out.push(format!("+{}", a));
+            }
+            (Some(e), None) => out.push(format!("-{}", e)),
+            (None, Some(a)) => out.push(format!("+{}", a)),
+            (None, None) => unreachable!(),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    // `Mint::new` reads `REGENERATE_VAR`, and `regenerate_mode_overwrites_golden_file`
+    // mutates it, so every test here needs the same lock — otherwise the
+    // process-global env var can leak between tests running concurrently.
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("goldenfile-test-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn matches_identical_golden_file() {
+        let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let dir = temp_dir("match");
+        fs::write(dir.join("out.txt"), "hello\n").unwrap();
+
+        let mut mint = Mint::new(&dir);
+        {
+            let mut w = mint.new_goldenfile("out.txt").unwrap();
+            writeln!(w, "hello").unwrap();
+        }
+        drop(mint);
+    }
+
+    #[test]
+    #[should_panic(expected = "golden file mismatch")]
+    fn panics_on_mismatch() {
+        let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let dir = temp_dir("mismatch");
+        fs::write(dir.join("out.txt"), "hello\n").unwrap();
+
+        let mut mint = Mint::new(&dir);
+        {
+            let mut w = mint.new_goldenfile("out.txt").unwrap();
+            writeln!(w, "goodbye").unwrap();
+        }
+        drop(mint);
+    }
+
+    #[test]
+    fn regenerate_mode_overwrites_golden_file() {
+        let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let dir = temp_dir("regen");
+        fs::write(dir.join("out.txt"), "old\n").unwrap();
+        env::set_var(REGENERATE_VAR, "1");
+
+        let mut mint = Mint::new(&dir);
+        {
+            let mut w = mint.new_goldenfile("out.txt").unwrap();
+            writeln!(w, "new").unwrap();
+        }
+        drop(mint);
+
+        env::remove_var(REGENERATE_VAR);
+        assert_eq!(fs::read_to_string(dir.join("out.txt")).unwrap(), "new\n");
+    }
+}
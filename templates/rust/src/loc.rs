@@ -0,0 +1,540 @@
+//! Line-of-code counting driven by an external language-definition file.
+//!
+//! Language rules (extensions, comment tokens, quote delimiters) live in a
+//! JSON file rather than in code so new languages can be added without a
+//! recompile. See `languages.json` for the expected shape.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// Comment/quote rules for a single language, as loaded from the language
+/// definition file.
+#[derive(Debug, Clone, Default)]
+pub struct LanguageDef {
+    pub extensions: Vec<String>,
+    pub line_comment: Vec<String>,
+    pub multi_line_comments: Vec<(String, String)>,
+    pub quotes: Vec<(String, String)>,
+}
+
+/// Per-language line counts.
+#[derive(Debug, Clone, Default)]
+pub struct LanguageStats {
+    pub files: usize,
+    pub lines: usize,
+    pub code: usize,
+    pub comments: usize,
+    pub blanks: usize,
+}
+
+impl LanguageStats {
+    fn add(&mut self, other: &LanguageStats) {
+        self.files += other.files;
+        self.lines += other.lines;
+        self.code += other.code;
+        self.comments += other.comments;
+        self.blanks += other.blanks;
+    }
+}
+
+/// Aggregated result of a `count_path` scan.
+#[derive(Debug, Clone, Default)]
+pub struct Report {
+    pub languages: HashMap<String, LanguageStats>,
+    pub total: LanguageStats,
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut rows: Vec<(&String, &LanguageStats)> = self.languages.iter().collect();
+        rows.sort_by(|a, b| b.1.code.cmp(&a.1.code).then_with(|| a.0.cmp(b.0)));
+
+        writeln!(
+            f,
+            "{:<12} {:>8} {:>8} {:>8} {:>8}",
+            "Language", "Files", "Code", "Comments", "Blanks"
+        )?;
+        for (name, stats) in rows {
+            writeln!(
+                f,
+                "{:<12} {:>8} {:>8} {:>8} {:>8}",
+                name, stats.files, stats.code, stats.comments, stats.blanks
+            )?;
+        }
+        writeln!(
+            f,
+            "{:<12} {:>8} {:>8} {:>8} {:>8}",
+            "Total", self.total.files, self.total.code, self.total.comments, self.total.blanks
+        )
+    }
+}
+
+/// Load language definitions from a JSON file shaped like `languages.json`.
+pub fn load_language_defs(path: &Path) -> Result<HashMap<String, LanguageDef>, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("reading {}: {}", path.display(), e))?;
+    parse_language_defs(&text)
+}
+
+/// A deliberately small JSON parser, just enough to read the
+/// `{ "Lang": { "extensions": [...], "line_comment": [...], ... } }` shape
+/// used by the language-definition file, without pulling in a dependency.
+fn parse_language_defs(text: &str) -> Result<HashMap<String, LanguageDef>, String> {
+    let mut chars = text.chars().peekable();
+    skip_ws(&mut chars);
+    expect(&mut chars, '{')?;
+    let mut defs = HashMap::new();
+
+    skip_ws(&mut chars);
+    if peek(&mut chars) == Some('}') {
+        chars.next();
+        return Ok(defs);
+    }
+
+    loop {
+        skip_ws(&mut chars);
+        let name = parse_json_string(&mut chars)?;
+        skip_ws(&mut chars);
+        expect(&mut chars, ':')?;
+        skip_ws(&mut chars);
+        let def = parse_language_def(&mut chars)?;
+        defs.insert(name, def);
+        skip_ws(&mut chars);
+        match peek(&mut chars) {
+            Some(',') => {
+                chars.next();
+            }
+            Some('}') => {
+                chars.next();
+                break;
+            }
+            other => return Err(format!("expected ',' or '}}', found {:?}", other)),
+        }
+    }
+    Ok(defs)
+}
+
+fn parse_language_def(chars: &mut std::iter::Peekable<std::str::Chars>) -> Result<LanguageDef, String> {
+    expect(chars, '{')?;
+    let mut def = LanguageDef::default();
+    skip_ws(chars);
+    if peek(chars) == Some('}') {
+        chars.next();
+        return Ok(def);
+    }
+    loop {
+        skip_ws(chars);
+        let key = parse_json_string(chars)?;
+        skip_ws(chars);
+        expect(chars, ':')?;
+        skip_ws(chars);
+        match key.as_str() {
+            "extensions" | "line_comment" => {
+                let values = parse_string_array(chars)?;
+                if key == "extensions" {
+                    def.extensions = values;
+                } else {
+                    def.line_comment = values;
+                }
+            }
+            "multi_line_comments" | "quotes" => {
+                let pairs = parse_pair_array(chars)?;
+                if key == "multi_line_comments" {
+                    def.multi_line_comments = pairs;
+                } else {
+                    def.quotes = pairs;
+                }
+            }
+            other => return Err(format!("unknown language-def key {:?}", other)),
+        }
+        skip_ws(chars);
+        match peek(chars) {
+            Some(',') => {
+                chars.next();
+            }
+            Some('}') => {
+                chars.next();
+                break;
+            }
+            other => return Err(format!("expected ',' or '}}', found {:?}", other)),
+        }
+    }
+    Ok(def)
+}
+
+fn parse_string_array(chars: &mut std::iter::Peekable<std::str::Chars>) -> Result<Vec<String>, String> {
+    expect(chars, '[')?;
+    let mut values = Vec::new();
+    skip_ws(chars);
+    if peek(chars) == Some(']') {
+        chars.next();
+        return Ok(values);
+    }
+    loop {
+        skip_ws(chars);
+        values.push(parse_json_string(chars)?);
+        skip_ws(chars);
+        match peek(chars) {
+            Some(',') => {
+                chars.next();
+            }
+            Some(']') => {
+                chars.next();
+                break;
+            }
+            other => return Err(format!("expected ',' or ']', found {:?}", other)),
+        }
+    }
+    Ok(values)
+}
+
+fn parse_pair_array(
+    chars: &mut std::iter::Peekable<std::str::Chars>,
+) -> Result<Vec<(String, String)>, String> {
+    expect(chars, '[')?;
+    let mut pairs = Vec::new();
+    skip_ws(chars);
+    if peek(chars) == Some(']') {
+        chars.next();
+        return Ok(pairs);
+    }
+    loop {
+        skip_ws(chars);
+        let mut inner = parse_string_array(chars)?;
+        if inner.len() != 2 {
+            return Err(format!("expected a [start, end] pair, got {:?}", inner));
+        }
+        let end = inner.pop().unwrap();
+        let start = inner.pop().unwrap();
+        pairs.push((start, end));
+        skip_ws(chars);
+        match peek(chars) {
+            Some(',') => {
+                chars.next();
+            }
+            Some(']') => {
+                chars.next();
+                break;
+            }
+            other => return Err(format!("expected ',' or ']', found {:?}", other)),
+        }
+    }
+    Ok(pairs)
+}
+
+fn parse_json_string(chars: &mut std::iter::Peekable<std::str::Chars>) -> Result<String, String> {
+    expect(chars, '"')?;
+    let mut out = String::new();
+    loop {
+        match chars.next() {
+            Some('"') => break,
+            Some('\\') => match chars.next() {
+                Some('n') => out.push('\n'),
+                Some('t') => out.push('\t'),
+                Some(c) => out.push(c),
+                None => return Err("unterminated escape in string".to_string()),
+            },
+            Some(c) => out.push(c),
+            None => return Err("unterminated string".to_string()),
+        }
+    }
+    Ok(out)
+}
+
+fn skip_ws(chars: &mut std::iter::Peekable<std::str::Chars>) {
+    while matches!(chars.peek(), Some(c) if c.is_whitespace()) {
+        chars.next();
+    }
+}
+
+fn peek(chars: &mut std::iter::Peekable<std::str::Chars>) -> Option<char> {
+    chars.peek().copied()
+}
+
+fn expect(chars: &mut std::iter::Peekable<std::str::Chars>, want: char) -> Result<(), String> {
+    match chars.next() {
+        Some(c) if c == want => Ok(()),
+        other => Err(format!("expected {:?}, found {:?}", want, other)),
+    }
+}
+
+/// Pick a `LanguageDef` for `path` based on its extension.
+fn lookup_language<'a>(
+    defs: &'a HashMap<String, LanguageDef>,
+    path: &Path,
+) -> Option<(&'a str, &'a LanguageDef)> {
+    let ext = path.extension()?.to_str()?;
+    defs.iter()
+        .find(|(_, def)| def.extensions.iter().any(|e| e == ext))
+        .map(|(name, def)| (name.as_str(), def))
+}
+
+/// Byte length of the first character of `s`, for advancing a byte index
+/// one character at a time without landing mid-codepoint.
+fn char_len_at(s: &str) -> usize {
+    s.chars().next().map_or(1, |c| c.len_utf8())
+}
+
+/// Count one file's lines as code/comment/blank using `def`'s tokens.
+///
+/// Tracks multi-line comment nesting depth and the active quote char so
+/// that comment tokens inside string literals are never mistaken for real
+/// comments.
+fn count_file(text: &str, def: &LanguageDef) -> LanguageStats {
+    let mut stats = LanguageStats {
+        files: 1,
+        ..Default::default()
+    };
+
+    let mut comment_depth: usize = 0;
+    let mut active_comment_end: Option<&str> = None;
+    let mut in_quote: Option<&str> = None;
+
+    for line in text.lines() {
+        stats.lines += 1;
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            stats.blanks += 1;
+            continue;
+        }
+
+        let mut saw_code = false;
+        let mut saw_comment = comment_depth > 0;
+        let bytes = line.as_bytes();
+        let mut i = 0;
+        'chars: while i < bytes.len() {
+            let rest = &line[i..];
+
+            if let Some(end) = active_comment_end {
+                if rest.starts_with(end) {
+                    comment_depth -= 1;
+                    i += end.len();
+                    if comment_depth == 0 {
+                        active_comment_end = None;
+                    }
+                    continue;
+                }
+                // Still allow nested opens of the same comment style.
+                for (start, cend) in &def.multi_line_comments {
+                    if cend == end && rest.starts_with(start.as_str()) {
+                        comment_depth += 1;
+                        i += start.len();
+                        continue 'chars;
+                    }
+                }
+                i += char_len_at(rest);
+                continue;
+            }
+
+            if let Some(q) = in_quote {
+                if let Some(escaped) = rest.strip_prefix('\\') {
+                    if let Some(c) = escaped.chars().next() {
+                        i += 1 + c.len_utf8();
+                        continue;
+                    }
+                }
+                if rest.starts_with(q) {
+                    in_quote = None;
+                }
+                i += char_len_at(rest);
+                saw_code = true;
+                continue;
+            }
+
+            if let Some((_, end)) = def
+                .quotes
+                .iter()
+                .find(|(start, _)| rest.starts_with(start.as_str()))
+            {
+                in_quote = Some(end.as_str());
+                i += 1;
+                saw_code = true;
+                continue;
+            }
+
+            if let Some((start, end)) = def
+                .multi_line_comments
+                .iter()
+                .find(|(start, _)| rest.starts_with(start.as_str()))
+            {
+                comment_depth = 1;
+                active_comment_end = Some(end.as_str());
+                i += start.len();
+                saw_comment = true;
+                continue;
+            }
+
+            if def
+                .line_comment
+                .iter()
+                .any(|token| rest.starts_with(token.as_str()))
+            {
+                saw_comment = true;
+                break;
+            }
+
+            let c = rest.chars().next().expect("i < bytes.len() implies a char remains");
+            if !c.is_whitespace() {
+                saw_code = true;
+            }
+            i += c.len_utf8();
+        }
+
+        if saw_code {
+            stats.code += 1;
+        } else if saw_comment {
+            stats.comments += 1;
+        } else {
+            stats.blanks += 1;
+        }
+    }
+
+    stats
+}
+
+/// Walk `root`, classify each recognized file by extension, and return an
+/// aggregated report of code/comment/blank line counts per language.
+pub fn count_path(root: &Path, defs: &HashMap<String, LanguageDef>) -> Report {
+    let mut report = Report::default();
+    let mut stack = vec![root.to_path_buf()];
+
+    while let Some(dir) = stack.pop() {
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                stack.push(path);
+                continue;
+            }
+            let Some((name, def)) = lookup_language(defs, &path) else {
+                continue;
+            };
+            let Ok(text) = fs::read_to_string(&path) else {
+                continue;
+            };
+            let stats = count_file(&text, def);
+            report.total.add(&stats);
+            report
+                .languages
+                .entry(name.to_string())
+                .or_default()
+                .add(&stats);
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rust_def() -> LanguageDef {
+        LanguageDef {
+            extensions: vec!["rs".to_string()],
+            line_comment: vec!["//".to_string()],
+            multi_line_comments: vec![("/*".to_string(), "*/".to_string())],
+            quotes: vec![("\"".to_string(), "\"".to_string())],
+        }
+    }
+
+    #[test]
+    fn classifies_code_comment_and_blank_lines() {
+        let src = "fn main() {\n\n    // a comment\n    let x = 1;\n}\n";
+        let stats = count_file(src, &rust_def());
+        assert_eq!(stats.blanks, 1);
+        assert_eq!(stats.comments, 1);
+        assert_eq!(stats.code, 3);
+    }
+
+    #[test]
+    fn ignores_comment_tokens_inside_strings() {
+        let src = "let s = \"// not a comment\";\n";
+        let stats = count_file(src, &rust_def());
+        assert_eq!(stats.code, 1);
+        assert_eq!(stats.comments, 0);
+    }
+
+    #[test]
+    fn handles_multi_byte_utf8_in_strings_and_comments() {
+        let src = "let s = \"héllo wörld\"; // caf\u{e9} ☕\n";
+        let stats = count_file(src, &rust_def());
+        assert_eq!(stats.code, 1);
+        assert_eq!(stats.comments, 0);
+    }
+
+    #[test]
+    fn handles_nested_multi_line_comments() {
+        let src = "/* outer /* inner */ still comment */\nlet x = 1;\n";
+        let stats = count_file(src, &rust_def());
+        assert_eq!(stats.comments, 1);
+        assert_eq!(stats.code, 1);
+    }
+
+    #[test]
+    fn parses_language_defs_from_json() {
+        let json = r#"{
+            "Rust": {
+                "extensions": ["rs"],
+                "line_comment": ["//"],
+                "multi_line_comments": [["/*", "*/"]],
+                "quotes": [["\"", "\""]]
+            }
+        }"#;
+        let defs = parse_language_defs(json).expect("valid json");
+        let rust = defs.get("Rust").expect("Rust entry present");
+        assert_eq!(rust.extensions, vec!["rs"]);
+        assert_eq!(rust.multi_line_comments, vec![("/*".to_string(), "*/".to_string())]);
+    }
+
+    fn python_def() -> LanguageDef {
+        LanguageDef {
+            extensions: vec!["py".to_string()],
+            line_comment: vec!["#".to_string()],
+            multi_line_comments: vec![],
+            quotes: vec![("\"".to_string(), "\"".to_string())],
+        }
+    }
+
+    fn temp_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("loc-test-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn count_path_walks_subdirectories_and_aggregates_by_language() {
+        let dir = temp_dir("count-path");
+        fs::write(dir.join("main.rs"), "fn main() {\n    // hi\n}\n").unwrap();
+        fs::create_dir_all(dir.join("sub")).unwrap();
+        fs::write(dir.join("sub").join("lib.rs"), "let x = 1;\n").unwrap();
+        fs::write(dir.join("script.py"), "# comment\nprint(1)\n").unwrap();
+
+        let mut defs = HashMap::new();
+        defs.insert("Rust".to_string(), rust_def());
+        defs.insert("Python".to_string(), python_def());
+
+        let report = count_path(&dir, &defs);
+
+        let rust = report.languages.get("Rust").expect("Rust entry present");
+        assert_eq!(rust.files, 2);
+        assert_eq!(rust.code, 3);
+        assert_eq!(rust.comments, 1);
+
+        let python = report.languages.get("Python").expect("Python entry present");
+        assert_eq!(python.files, 1);
+        assert_eq!(python.code, 1);
+        assert_eq!(python.comments, 1);
+
+        assert_eq!(report.total.files, 3);
+        assert_eq!(report.total.code, 4);
+        assert_eq!(report.total.comments, 2);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
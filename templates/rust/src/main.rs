@@ -1,5 +1,15 @@
 //! My Rust Project
 
+#[cfg(test)]
+mod goldenfile;
+mod loc;
+mod rsmonad;
+
+use std::env;
+use std::path::{Path, PathBuf};
+
+use rsmonad::{asum, guard, Applicative, Functor, List, Monad};
+
 fn hello(name: &str) -> String {
     format!("Hello, {}!", name)
 }
@@ -8,8 +18,72 @@ fn add(a: i32, b: i32) -> i32 {
     a + b
 }
 
+/// Look for `languages.json` next to the running binary first, falling
+/// back to the current directory, via `rsmonad::asum` so the first
+/// candidate that actually exists wins.
+fn find_language_defs() -> PathBuf {
+    let exe_dir = env::current_exe().ok().and_then(|p| p.parent().map(Path::to_path_buf));
+    let candidates: Vec<Box<dyn FnOnce() -> Option<PathBuf>>> = vec![
+        Box::new(move || exe_dir.map(|d| d.join("languages.json")).filter(|p| p.exists())),
+        Box::new(|| Some(PathBuf::from("languages.json")).filter(|p| p.exists())),
+    ];
+    asum(candidates).unwrap_or_else(|| PathBuf::from("languages.json"))
+}
+
+/// Combine two optional totals via the `Applicative` `fmap`/`apply` pair
+/// instead of `Option::zip`, so a missing operand short-circuits the sum.
+fn add_options(a: Option<i64>, b: Option<i64>) -> Option<i64> {
+    let add_b = a.fmap(|x| move |y: i64| x + y);
+    b.apply(add_b)
+}
+
+/// Fold the per-language code-line counts back into a total using
+/// `Applicative::pure` as the starting accumulator.
+fn total_code_lines(report: &loc::Report) -> Option<i64> {
+    report
+        .languages
+        .values()
+        .fold(Applicative::pure(0i64), |acc, stats| {
+            add_options(acc, Some(stats.code as i64))
+        })
+}
+
+/// Languages with exactly one counted file, built as a `List`
+/// comprehension (`Monad::bind` + `guard`) over the report.
+fn singly_represented_languages(report: &loc::Report) -> List<String> {
+    let names: Vec<String> = report.languages.keys().cloned().collect();
+    List::new(names).bind(|name| {
+        let files = report.languages[&name].files;
+        guard(files == 1).bind(move |_| List::new(vec![name.clone()]))
+    })
+}
+
 fn main() {
     println!("{}", hello("Rust"));
+
+    let root = env::args().nth(1).unwrap_or_else(|| ".".to_string());
+    let defs_path = find_language_defs();
+    match loc::load_language_defs(&defs_path) {
+        Ok(defs) => {
+            let report = loc::count_path(Path::new(&root), &defs);
+            print!("{}", report);
+
+            if let Some(total) = total_code_lines(&report) {
+                println!("code lines (via rsmonad fold): {}", total);
+            }
+
+            let singles = singly_represented_languages(&report);
+            let count = singles.iter().count();
+            if singles.is_empty() {
+                println!("no single-file languages");
+            } else {
+                let mut names = singles.into_vec();
+                names.sort();
+                println!("single-file languages ({}): {}", count, names.join(", "));
+            }
+        }
+        Err(e) => eprintln!("could not load {}: {}", defs_path.display(), e),
+    }
 }
 
 #[cfg(test)]
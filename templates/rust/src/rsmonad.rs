@@ -0,0 +1,331 @@
+//! A small monadic combinator toolkit: `Functor`/`Applicative`/`Monad`
+//! implemented for `Option`, `Result`, and a `List<T>` wrapper around
+//! `Vec<T>`. `List` additionally overloads `>>` as an ergonomic bind
+//! operator, via `Shr`, so comprehension-style chains read like
+//! do-notation (the orphan rules rule this overload out for the foreign
+//! `Option`/`Result` types, so those still chain through `.bind(...)`).
+//! `guard` is `List`-specific for the same reason: there's no generic
+//! `Monad`-returning "empty" to fall back to, so it only makes sense as
+//! a `List` filter.
+//!
+//! ```ignore
+//! use crate::list;
+//! use crate::rsmonad::{guard, List};
+//!
+//! let z = 20;
+//! let triples: List<(i32, i32)> = list![1..20] >> |x| {
+//!     list![x..20] >> move |y| guard(x + y == z) >> move |_| list![(x, y)]
+//! };
+//! ```
+
+use std::ops::Shr;
+
+/// Types that can have a plain function mapped over their contents.
+pub trait Functor<A> {
+    type Target<B>;
+
+    fn fmap<B>(self, f: impl FnMut(A) -> B) -> Self::Target<B>;
+}
+
+/// Types that can lift a value and apply a wrapped function to a wrapped
+/// value.
+pub trait Applicative<A>: Functor<A> {
+    fn pure(value: A) -> Self;
+
+    fn apply<B, F>(self, f: Self::Target<F>) -> Self::Target<B>
+    where
+        F: FnMut(A) -> B;
+}
+
+/// Types that can sequence computations, feeding each result into the
+/// next step.
+pub trait Monad<A>: Applicative<A> {
+    fn bind<B>(self, f: impl FnMut(A) -> Self::Target<B>) -> Self::Target<B>;
+}
+
+// ---------------------------------------------------------------- Option
+
+impl<A> Functor<A> for Option<A> {
+    type Target<B> = Option<B>;
+
+    fn fmap<B>(self, f: impl FnMut(A) -> B) -> Option<B> {
+        self.map(f)
+    }
+}
+
+impl<A> Applicative<A> for Option<A> {
+    fn pure(value: A) -> Self {
+        Some(value)
+    }
+
+    fn apply<B, F>(self, f: Option<F>) -> Option<B>
+    where
+        F: FnMut(A) -> B,
+    {
+        match (self, f) {
+            (Some(a), Some(mut f)) => Some(f(a)),
+            _ => None,
+        }
+    }
+}
+
+impl<A> Monad<A> for Option<A> {
+    fn bind<B>(self, f: impl FnMut(A) -> Option<B>) -> Option<B> {
+        self.and_then(f)
+    }
+}
+
+// ---------------------------------------------------------------- Result
+
+impl<A, E> Functor<A> for Result<A, E> {
+    type Target<B> = Result<B, E>;
+
+    fn fmap<B>(self, f: impl FnMut(A) -> B) -> Result<B, E> {
+        self.map(f)
+    }
+}
+
+impl<A, E> Applicative<A> for Result<A, E> {
+    fn pure(value: A) -> Self {
+        Ok(value)
+    }
+
+    fn apply<B, F>(self, f: Result<F, E>) -> Result<B, E>
+    where
+        F: FnMut(A) -> B,
+    {
+        match (self, f) {
+            (Ok(a), Ok(mut f)) => Ok(f(a)),
+            (Err(e), _) => Err(e),
+            (_, Err(e)) => Err(e),
+        }
+    }
+}
+
+impl<A, E> Monad<A> for Result<A, E> {
+    fn bind<B>(self, f: impl FnMut(A) -> Result<B, E>) -> Result<B, E> {
+        self.and_then(f)
+    }
+}
+
+// ------------------------------------------------------------------ List
+
+/// A thin `Vec<T>` wrapper with `Monad`'s `bind` defined as flat-map, so
+/// it models list comprehensions / nondeterministic computation.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct List<T>(pub Vec<T>);
+
+impl<T> List<T> {
+    pub fn new(items: Vec<T>) -> Self {
+        List(items)
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.0.iter()
+    }
+}
+
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        List(iter.into_iter().collect())
+    }
+}
+
+impl<A> Functor<A> for List<A> {
+    type Target<B> = List<B>;
+
+    fn fmap<B>(self, f: impl FnMut(A) -> B) -> List<B> {
+        List(self.0.into_iter().map(f).collect())
+    }
+}
+
+impl<A: Clone> Applicative<A> for List<A> {
+    fn pure(value: A) -> Self {
+        List(vec![value])
+    }
+
+    /// The cartesian product of functions and values: every `f` is applied
+    /// to every element of `self`. This needs `A: Clone` since each value
+    /// is fed to every function in `fs`.
+    fn apply<B, F>(self, fs: List<F>) -> List<B>
+    where
+        F: FnMut(A) -> B,
+    {
+        let mut out = Vec::new();
+        for mut f in fs.0 {
+            for a in self.0.iter().cloned() {
+                out.push(f(a));
+            }
+        }
+        List(out)
+    }
+}
+
+impl<A: Clone> Monad<A> for List<A> {
+    fn bind<B>(self, mut f: impl FnMut(A) -> List<B>) -> List<B> {
+        List(self.0.into_iter().flat_map(|a| f(a).0).collect())
+    }
+}
+
+/// Bind-as-`>>`, so chains read like do-notation:
+/// `list![1, 2] >> |x| list![x, x + 10]`.
+impl<A: Clone, B, F> Shr<F> for List<A>
+where
+    F: FnMut(A) -> List<B>,
+{
+    type Output = List<B>;
+
+    fn shr(self, f: F) -> List<B> {
+        self.bind(f)
+    }
+}
+
+/// Build a `List` from a range (`list![1..20]`) or an explicit,
+/// comma-separated item list (`list![(x, y)]`, `list![1, 2, 3]`).
+#[macro_export]
+macro_rules! list {
+    ($start:tt .. $end:tt) => {
+        $crate::rsmonad::List::from_iter(($start)..($end))
+    };
+    ($($items:expr),+ $(,)?) => {
+        $crate::rsmonad::List::new(vec![$($items),+])
+    };
+}
+
+/// `List`-specific guard for comprehensions: `guard(true)` is a singleton
+/// unit-ish list, `guard(false)` is empty. This is not a generic
+/// `Monad`-returning combinator (there's no `Option`/`Result` consumer for
+/// it); it only filters `List` comprehensions built out of `bind`/`>>`
+/// chains, e.g. `... >> |_| guard(cond) >> |_| list![result]`.
+pub fn guard(condition: bool) -> List<()> {
+    if condition {
+        List(vec![()])
+    } else {
+        List(vec![])
+    }
+}
+
+/// Return the first `Some`/non-empty alternative produced by `thunks`,
+/// evaluating them lazily in order and stopping at the first success.
+pub fn asum<T>(thunks: impl IntoIterator<Item = impl FnOnce() -> Option<T>>) -> Option<T> {
+    for thunk in thunks {
+        if let Some(value) = thunk() {
+            return Some(value);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn option_bind_chains() {
+        let result = Some(2)
+            .bind(|x: i32| Some(x + 3))
+            .bind(|y: i32| if y > 0 { Some(y * 2) } else { None });
+        assert_eq!(result, Some(10));
+    }
+
+    #[test]
+    fn result_bind_short_circuits_on_err() {
+        let fail: Result<i32, &str> = Err("boom");
+        let result = fail.bind(|x: i32| Ok::<i32, &str>(x + 1));
+        assert_eq!(result, Err("boom"));
+    }
+
+    #[test]
+    fn list_bind_flat_maps() {
+        let doubled = list![1, 2, 3] >> |x: i32| list![x, x * 10];
+        assert_eq!(doubled, List::new(vec![1, 10, 2, 20, 3, 30]));
+    }
+
+    #[test]
+    fn list_macro_builds_from_range() {
+        let xs: List<i32> = list![1..4];
+        assert_eq!(xs, List::new(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn guard_filters_comprehensions() {
+        let z = 5;
+        let pairs: List<(i32, i32)> = list![1, 2, 3] >> |x: i32| {
+            list![1, 2, 3] >> move |y: i32| guard(x + y == z) >> move |_| list![(x, y)]
+        };
+        assert_eq!(pairs, List::new(vec![(2, 3), (3, 2)]));
+    }
+
+    #[test]
+    fn asum_returns_first_present_alternative() {
+        let thunks: [fn() -> Option<i32>; 3] = [|| None, || Some(2), || Some(3)];
+        assert_eq!(asum(thunks), Some(2));
+    }
+
+    #[test]
+    fn asum_returns_none_when_all_absent() {
+        let thunks: [fn() -> Option<i32>; 2] = [|| None, || None];
+        assert_eq!(asum(thunks), None);
+    }
+
+    #[test]
+    fn fmap_transforms_the_wrapped_value() {
+        assert_eq!(Some(2).fmap(|x: i32| x * 10), Some(20));
+        assert_eq!(Ok::<i32, &str>(2).fmap(|x: i32| x * 10), Ok(20));
+        assert_eq!(
+            List::new(vec![1, 2, 3]).fmap(|x: i32| x * 10),
+            List::new(vec![10, 20, 30])
+        );
+    }
+
+    #[test]
+    fn pure_lifts_a_bare_value() {
+        let wrapped: Option<i32> = Applicative::pure(5);
+        assert_eq!(wrapped, Some(5));
+
+        let wrapped: Result<i32, &str> = Applicative::pure(5);
+        assert_eq!(wrapped, Ok(5));
+
+        let wrapped: List<i32> = Applicative::pure(5);
+        assert_eq!(wrapped, List::new(vec![5]));
+    }
+
+    #[test]
+    fn apply_runs_a_wrapped_function_over_a_wrapped_value() {
+        assert_eq!(Some(3).apply(Some(|x: i32| x + 1)), Some(4));
+        assert_eq!(Some(3).apply(None::<fn(i32) -> i32>), None);
+
+        let err: Result<i32, &str> = Err("boom");
+        assert_eq!(Ok::<i32, &str>(3).apply(Ok::<_, &str>(|x: i32| x + 1)), Ok(4));
+        assert_eq!(err.apply(Ok::<_, &str>(|x: i32| x + 1)), Err("boom"));
+
+        let doubled = List::new(vec![1, 2]).apply(List::new(vec![|x: i32| x * 2, |x: i32| x * 3]));
+        assert_eq!(doubled, List::new(vec![2, 4, 3, 6]));
+    }
+
+    #[test]
+    fn list_inherent_helpers_round_trip() {
+        let xs = List::new(vec![3, 1, 2]);
+        assert!(!xs.is_empty());
+        assert_eq!(xs.iter().sum::<i32>(), 6);
+        assert_eq!(xs.into_vec(), vec![3, 1, 2]);
+        assert!(List::<i32>::new(vec![]).is_empty());
+    }
+}